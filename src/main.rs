@@ -2,11 +2,24 @@ use aws_sdk_s3::{config::{BehaviorVersion, Credentials, Region}, primitives::Byt
 use sha2::{Digest, Sha256};
 use std::{
     error::Error,
+    fmt,
     io::{self, Read, Write},
 };
-use tokio;
+#[cfg(feature = "async")]
+use tokio::io::{AsyncRead, AsyncWrite};
 use ulid::Ulid;
 
+mod tlv;
+
+/// Well-known TLV types in a record's header (see [`Record::encode_body`]). Readers must treat
+/// any other *even* type as a hard error and may silently skip any other *odd* type.
+const TLV_TYPE_CREATED_AT: u64 = 1;
+const TLV_TYPE_CONTENT_ENCODING: u64 = 3;
+
+/// `(created_at_ms, content_encoding, payload)`, as split out of a record's wire body by
+/// [`Record::decode_body`].
+type DecodedBody = (Option<u64>, Option<Vec<u8>>, Vec<u8>);
+
 /// Represents a record in the WAL. ULID and checksum are useful and provide a predictable amount
 /// of metadata overhead (ULID is not serialized and 32 bytes for checksum).
 #[derive(Debug)]
@@ -14,6 +27,105 @@ pub struct Record {
     pub ulid: Ulid,         // ULID as the unique identifier
     pub data: Vec<u8>,      // Data payload
     pub checksum: [u8; 32], // SHA-256 checkum for integrity
+
+    /// Unix millis the record was created, if set (TLV type 1).
+    pub created_at_ms: Option<u64>,
+    /// Content-encoding tag for `data`, if set (TLV type 3).
+    pub content_encoding: Option<Vec<u8>>,
+}
+
+/// Error produced while decoding a [`Record`] frame.
+#[derive(Debug)]
+pub enum DecodeError {
+    /// The underlying reader failed (including unexpected EOF mid-frame).
+    Io(io::Error),
+    /// The frame's embedded checksum did not match the SHA-256 of its payload.
+    ChecksumMismatch,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::Io(e) => write!(f, "io error decoding record: {e}"),
+            DecodeError::ChecksumMismatch => write!(f, "checksum mismatch decoding record"),
+        }
+    }
+}
+
+impl Error for DecodeError {}
+
+impl From<io::Error> for DecodeError {
+    fn from(e: io::Error) -> Self {
+        DecodeError::Io(e)
+    }
+}
+
+impl From<DecodeError> for io::Error {
+    fn from(e: DecodeError) -> Self {
+        match e {
+            DecodeError::Io(e) => e,
+            DecodeError::ChecksumMismatch => io::Error::new(io::ErrorKind::InvalidData, "checksum mismatch"),
+        }
+    }
+}
+
+/// A sink that serialization code writes raw bytes to. Blanket-implemented for any
+/// `std::io::Write` (so `Vec<u8>` and friends work directly), and also implemented by
+/// [`LengthCalculatingWriter`], which only counts bytes instead of storing them.
+pub trait Writer {
+    fn write_all(&mut self, bytes: &[u8]);
+}
+
+impl<W: Write> Writer for W {
+    fn write_all(&mut self, bytes: &[u8]) {
+        Write::write_all(self, bytes).expect("writing to an in-memory buffer cannot fail");
+    }
+}
+
+/// Implements [`Writer`] by only summing byte counts. This lets [`Writeable::serialized_length`]
+/// reuse the exact same `write` logic as the real encode path, instead of a second hand-written
+/// formula that can silently drift out of sync with it.
+#[derive(Debug, Default)]
+pub struct LengthCalculatingWriter {
+    len: usize,
+}
+
+impl LengthCalculatingWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl Writer for LengthCalculatingWriter {
+    fn write_all(&mut self, bytes: &[u8]) {
+        self.len += bytes.len();
+    }
+}
+
+/// A type that can serialize itself to a [`Writer`].
+pub trait Writeable {
+    fn write(&self, w: &mut impl Writer);
+
+    /// The exact number of bytes `write` will emit, computed by replaying `write` against a
+    /// [`LengthCalculatingWriter`] so it can never drift from the real encoding.
+    fn serialized_length(&self) -> usize {
+        let mut counter = LengthCalculatingWriter::new();
+        self.write(&mut counter);
+        counter.len()
+    }
+}
+
+/// A type that can deserialize itself from a [`Read`].
+pub trait Readable: Sized {
+    fn read(r: &mut impl Read) -> Result<Self, DecodeError>;
 }
 
 impl Record {
@@ -27,49 +139,385 @@ impl Record {
         checkum
     }
 
-    /// Creates a new record with a ULID and calculated checksum.
+    /// Creates a new record with a ULID and calculated checksum, with no TLV metadata set.
     pub fn new(data: Vec<u8>) -> Self {
         let ulid = Ulid::new();
-        let checksum = Self::calculate_checksum(&data);
-        Self {
+        let mut record = Self {
             ulid,
             data,
-            checksum,
-        }
+            checksum: [0u8; 32],
+            created_at_ms: None,
+            content_encoding: None,
+        };
+        record.checksum = Self::calculate_checksum(&record.encode_body());
+        record
+    }
+
+    /// Sets the created-at metadata field (TLV type 1, unix millis) and recomputes the checksum
+    /// over the new TLV-prefixed body.
+    pub fn with_created_at_ms(mut self, ms: u64) -> Self {
+        self.created_at_ms = Some(ms);
+        self.checksum = Self::calculate_checksum(&self.encode_body());
+        self
+    }
+
+    /// Sets the content-encoding metadata field (TLV type 3) and recomputes the checksum over
+    /// the new TLV-prefixed body.
+    pub fn with_content_encoding(mut self, encoding: Vec<u8>) -> Self {
+        self.content_encoding = Some(encoding);
+        self.checksum = Self::calculate_checksum(&self.encode_body());
+        self
     }
 
     /// Validates the record's checksum.
     pub fn validate_checksum(&self) -> bool {
-        self.checksum == Self::calculate_checksum(&self.data)
+        self.checksum == Self::calculate_checksum(&self.encode_body())
+    }
+
+    /// Builds the record's wire body: a BigSize-prefixed TLV header (see [`tlv`]) followed by
+    /// the raw payload. This is what `checksum` is computed over, so metadata is covered by the
+    /// same integrity check as the payload.
+    fn encode_body(&self) -> Vec<u8> {
+        let mut header = Vec::new();
+        if let Some(ms) = self.created_at_ms {
+            tlv::encode_field(&mut header, TLV_TYPE_CREATED_AT, &ms.to_be_bytes());
+        }
+        if let Some(encoding) = &self.content_encoding {
+            tlv::encode_field(&mut header, TLV_TYPE_CONTENT_ENCODING, encoding);
+        }
+
+        let mut body = Vec::with_capacity(9 + header.len() + self.data.len());
+        tlv::encode(&mut body, header.len() as u64);
+        body.extend_from_slice(&header);
+        body.extend_from_slice(&self.data);
+        body
+    }
+
+    /// Splits a wire body back into its TLV metadata fields and raw payload.
+    ///
+    /// An unrecognized *odd* TLV type is skipped (forward-compat: future writers may add them
+    /// freely); an unrecognized *even* type is a hard error, since it signals metadata the
+    /// writer considered non-optional.
+    fn decode_body(mut bytes: &[u8]) -> io::Result<DecodedBody> {
+        let header_len = tlv::decode(&mut bytes)? as usize;
+        if header_len > bytes.len() {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "TLV header length exceeds body",
+            ));
+        }
+        let (header_bytes, payload) = bytes.split_at(header_len);
+
+        let mut created_at_ms = None;
+        let mut content_encoding = None;
+        for field in tlv::decode_stream(header_bytes)? {
+            match field.kind {
+                TLV_TYPE_CREATED_AT => {
+                    let bytes: [u8; 8] = field.value.as_slice().try_into().map_err(|_| {
+                        io::Error::new(io::ErrorKind::InvalidData, "created-at field must be 8 bytes")
+                    })?;
+                    created_at_ms = Some(u64::from_be_bytes(bytes));
+                }
+                TLV_TYPE_CONTENT_ENCODING => content_encoding = Some(field.value),
+                kind if kind % 2 == 1 => {} // unknown odd type: tolerated, skipped
+                kind => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("unknown even TLV type {kind} cannot be ignored"),
+                    ));
+                }
+            }
+        }
+
+        Ok((created_at_ms, content_encoding, payload.to_vec()))
+    }
+
+    /// Encodes the record via [`Writeable`], pre-sizing the output buffer exactly once using
+    /// `serialized_length` instead of growing it on the fly.
+    pub fn encode(&self) -> Vec<u8> {
+        let len = self.serialized_length();
+        let mut out = Vec::with_capacity(len);
+        self.write(&mut out);
+        debug_assert_eq!(len, out.len());
+        out
     }
 
     /// Serializes the record into a byte buffer (excluding the ULID).
     pub fn to_bytes(&self) -> io::Result<Vec<u8>> {
-        // We create a `buf` with the appropriate capacity by extending the `Record`'s `data` field
-        // length by 32, which is the length of our checksum field.
-        let mut buf = Vec::with_capacity(self.data.len() + 32);
-        buf.write_all(&self.data)?; // Write `data` content
-        buf.write_all(&self.checksum)?; // Write `checksum`
-        Ok(buf)
+        Ok(self.encode())
     }
 
     /// Deserializes a record from a byte buffer.
-    pub fn from_bytes(ulid: Ulid, mut bytes: &[u8]) -> io::Result<Self> {
-        let data_len = bytes.len() - 32; // Remember: buf = data.len() + 32
-        let mut data = vec![0u8; data_len];
-        bytes.read_exact(&mut data)?;
+    pub fn from_bytes(ulid: Ulid, bytes: &[u8]) -> io::Result<Self> {
+        let mut cursor = bytes;
+        let mut record = Self::read(&mut cursor)?;
+        record.ulid = ulid;
+        Ok(record)
+    }
+
+    /// Appends this record to `out` as a length-framed entry: `[len: u32 BE][checksum: 32][body]`,
+    /// where `body` is the TLV header followed by the payload (see [`Record::encode_body`]).
+    ///
+    /// `len` covers the checksum plus the body, so a reader only needs the frame bytes
+    /// (no out-of-band size, unlike `to_bytes`/`from_bytes`) to know where the record ends.
+    pub fn encode_frame(&self, out: &mut Vec<u8>) {
+        let body = self.encode_body();
+        let len = (body.len() + 32) as u32;
+        out.extend_from_slice(&len.to_be_bytes());
+        out.extend_from_slice(&self.checksum);
+        out.extend_from_slice(&body);
+    }
+
+    /// Reads one length-framed entry off the front of `buf`, advancing it past the frame.
+    ///
+    /// The embedded checksum is verified against a fresh SHA-256 of the body; the ULID is not
+    /// part of the frame (frames are packed many-to-an-object, so there's no single object key
+    /// to derive it from), so a new one is minted for the decoded record.
+    pub fn decode_frame(buf: &mut &[u8]) -> io::Result<Self> {
+        if buf.len() < 4 {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "frame length truncated"));
+        }
+        let mut len_bytes = [0u8; 4];
+        buf.read_exact(&mut len_bytes)?;
+        let len = u32::from_be_bytes(len_bytes) as usize;
+
+        if len < 32 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "frame length shorter than checksum",
+            ));
+        }
 
-        let mut checksum = [0u8; 32];
-        bytes.read_exact(&mut checksum)?;
+        let mut frame = vec![0u8; len];
+        buf.read_exact(&mut frame)?;
+
+        let checksum: [u8; 32] = frame[..32].try_into().unwrap();
+        let body = &frame[32..];
+
+        if checksum != Self::calculate_checksum(body) {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "checksum mismatch"));
+        }
+
+        let (created_at_ms, content_encoding, data) = Self::decode_body(body)?;
 
         Ok(Self {
-            ulid,
+            ulid: Ulid::new(),
+            data,
+            checksum,
+            created_at_ms,
+            content_encoding,
+        })
+    }
+
+    /// Streams one frame out of `r` without buffering the whole segment, for replaying WAL
+    /// segments directly off an S3 `ByteStream` or a local file.
+    #[cfg(feature = "async")]
+    pub async fn try_decode_from(r: &mut (impl AsyncRead + Unpin)) -> Result<Self, DecodeError> {
+        use tokio::io::AsyncReadExt;
+
+        let mut len_bytes = [0u8; 4];
+        r.read_exact(&mut len_bytes).await?;
+        let len = u32::from_be_bytes(len_bytes) as usize;
+
+        if len < 32 {
+            return Err(DecodeError::Io(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "frame length shorter than checksum",
+            )));
+        }
+
+        // `read_exact` fills the buffer to its *length*, not its capacity - a buffer built with
+        // `Vec::with_capacity(len)` still has length 0, so it would read zero bytes here.
+        let mut frame = vec![0u8; len];
+        r.read_exact(&mut frame).await?;
+
+        let checksum: [u8; 32] = frame[..32].try_into().unwrap();
+        let body = &frame[32..];
+
+        if checksum != Self::calculate_checksum(body) {
+            return Err(DecodeError::ChecksumMismatch);
+        }
+
+        let (created_at_ms, content_encoding, data) = Self::decode_body(body)?;
+
+        Ok(Self {
+            ulid: Ulid::new(),
+            data,
+            checksum,
+            created_at_ms,
+            content_encoding,
+        })
+    }
+
+    /// Writes this record as a single frame to `w`.
+    #[cfg(feature = "async")]
+    pub async fn try_encode_to(&self, w: &mut (impl AsyncWrite + Unpin)) -> io::Result<()> {
+        use tokio::io::AsyncWriteExt;
+
+        let mut buf = Vec::with_capacity(self.data.len() + 45);
+        self.encode_frame(&mut buf);
+        w.write_all(&buf).await
+    }
+}
+
+impl Writeable for Record {
+    fn write(&self, w: &mut impl Writer) {
+        w.write_all(&self.encode_body());
+        w.write_all(&self.checksum);
+    }
+}
+
+impl Readable for Record {
+    /// Reads a record the same way `from_bytes` did: consumes `r` to completion, treats the
+    /// trailing 32 bytes as the checksum and everything before it as the TLV-prefixed body.
+    /// The ULID isn't part of the wire format, so a fresh one is minted here too.
+    fn read(r: &mut impl Read) -> Result<Self, DecodeError> {
+        let mut buf = Vec::new();
+        r.read_to_end(&mut buf)?;
+
+        if buf.len() < 32 {
+            return Err(DecodeError::Io(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "record shorter than checksum",
+            )));
+        }
+
+        let split = buf.len() - 32;
+        let body = &buf[..split];
+        let checksum: [u8; 32] = buf[split..].try_into().unwrap();
+
+        if checksum != Self::calculate_checksum(body) {
+            return Err(DecodeError::ChecksumMismatch);
+        }
+
+        let (created_at_ms, content_encoding, data) = Self::decode_body(body)?;
+
+        Ok(Self {
+            ulid: Ulid::new(),
             data,
             checksum,
+            created_at_ms,
+            content_encoding,
+        })
+    }
+}
+
+/// Derives a segment's S3 object key from its id, so the key can be recomputed from the id alone
+/// instead of threading a formatted string around (see [`MinioWAL::read_streaming`]).
+fn segment_key(id: Ulid) -> String {
+    format!("wal/seg/{id}.seg")
+}
+
+/// A batch of records packed into a single S3 object, each framed with
+/// [`Record::encode_frame`]. This amortizes the per-`put_object` cost of [`MinioWAL::append`]
+/// over many records instead of one object per record.
+pub struct Segment {
+    id: Ulid,
+    buf: Vec<u8>,
+    len: usize,
+}
+
+impl Segment {
+    /// Creates a new, empty segment with a fresh id.
+    pub fn new() -> Self {
+        Self {
+            id: Ulid::new(),
+            buf: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// The id this segment will be (or was) written and read back under.
+    pub fn id(&self) -> Ulid {
+        self.id
+    }
+
+    /// The S3 object key this segment will be (or was) written to.
+    pub fn key(&self) -> String {
+        segment_key(self.id)
+    }
+
+    /// The number of records framed into this segment so far.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Frames `record` and appends it to the segment.
+    pub fn push(&mut self, record: &Record) {
+        record.encode_frame(&mut self.buf);
+        self.len += 1;
+    }
+
+    /// The segment's wire bytes, ready to be written to a single S3 object.
+    pub fn to_bytes(&self) -> &[u8] {
+        &self.buf
+    }
+}
+
+impl Default for Segment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Writeable for Segment {
+    fn write(&self, w: &mut impl Writer) {
+        w.write_all(&self.buf);
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.buf.len()
+    }
+}
+
+impl Readable for Segment {
+    /// Reads `r` to completion and eagerly validates every frame in it (rather than lazily, the
+    /// way [`SegmentReader`] does), so a malformed segment is rejected up front.
+    fn read(r: &mut impl Read) -> Result<Self, DecodeError> {
+        let mut buf = Vec::new();
+        r.read_to_end(&mut buf)?;
+
+        let mut remaining: &[u8] = &buf;
+        let mut len = 0usize;
+        while !remaining.is_empty() {
+            Record::decode_frame(&mut remaining)?;
+            len += 1;
+        }
+
+        Ok(Self {
+            id: Ulid::new(),
+            buf,
+            len,
         })
     }
 }
 
+/// Iterates the frames out of a segment's bytes, yielding each [`Record`] in turn.
+pub struct SegmentReader<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> SegmentReader<'a> {
+    pub fn new(buf: &'a [u8]) -> Self {
+        Self { buf }
+    }
+}
+
+impl<'a> Iterator for SegmentReader<'a> {
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.buf.is_empty() {
+            return None;
+        }
+        Some(Record::decode_frame(&mut self.buf))
+    }
+}
+
 impl PartialEq for Record {
     fn eq(&self, other: &Self) -> bool {
         self.ulid == other.ulid
@@ -80,7 +528,7 @@ impl Eq for Record {}
 
 impl PartialOrd for Record {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.ulid.cmp(&other.ulid))
+        Some(self.cmp(other))
     }
 }
 
@@ -90,6 +538,56 @@ impl Ord for Record {
     }
 }
 
+/// Upper bound on how much of an object we buffer per `GetObject` request when reading in
+/// chunks, matching the 64 KiB default read-buffer size comparable serialization/streaming
+/// frameworks use.
+const MAX_BUF_SIZE: usize = 64 * 1024;
+
+/// Fetches the next bounded-size byte-range window of `key`, or `None` once the whole object has
+/// been read. `total_len` is populated from the first response's `Content-Range` header (the
+/// object's true length, not just this chunk's) so callers stop exactly at the object boundary
+/// instead of relying on a short final chunk — which never comes when the object's length is an
+/// exact multiple of `MAX_BUF_SIZE`, and would otherwise draw a `416 InvalidRange` on the next
+/// request.
+async fn fetch_chunk(
+    client: &Client,
+    bucket: &str,
+    key: &str,
+    offset: &mut u64,
+    total_len: &mut Option<u64>,
+) -> Result<Option<bytes::Bytes>, Box<dyn Error + Send + Sync>> {
+    if let Some(total) = *total_len {
+        if *offset >= total {
+            return Ok(None);
+        }
+    }
+
+    let range = format!("bytes={}-{}", offset, *offset + MAX_BUF_SIZE as u64 - 1);
+    let response = client
+        .get_object()
+        .bucket(bucket)
+        .key(key)
+        .range(range)
+        .send()
+        .await?;
+
+    if total_len.is_none() {
+        *total_len = response
+            .content_range()
+            .and_then(|cr| cr.rsplit('/').next())
+            .and_then(|len| len.parse::<u64>().ok());
+    }
+
+    let chunk = response.body.collect().await?.into_bytes();
+    *offset += chunk.len() as u64;
+
+    if chunk.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(chunk))
+}
+
 pub struct MinioWAL {
     client: Client,
     bucket: String,
@@ -101,10 +599,10 @@ impl MinioWAL {
             .region(Region::new("us-east-1"))
             .endpoint_url("http://127.0.0.1:9000")
             .credentials_provider(Credentials::new(
-                "admin", 
-                "password", 
-                None, 
-                None, 
+                "admin",
+                "password",
+                None,
+                None,
                 "static"
             ))
             .behavior_version(BehaviorVersion::latest())
@@ -114,6 +612,51 @@ impl MinioWAL {
 
         Ok(Self { client, bucket: "gulog-dev".to_string() })
     }
+
+    /// Reads `key` in bounded `MAX_BUF_SIZE` byte-range windows, verifying the checksum
+    /// incrementally as chunks arrive (withholding only the trailing 32 bytes from the hasher
+    /// until they're confirmed to be the checksum, not payload) instead of re-hashing the
+    /// assembled body afterward. Note this still returns a fully materialized [`Record`] — the
+    /// per-window network fetch is bounded, but the decoded record itself is not streamed to the
+    /// caller; only [`MinioWAL::read_streaming`] avoids holding a whole object's data at once.
+    async fn read_chunked(&self, ulid: Ulid, key: &str) -> Result<Record, Box<dyn Error + Send + Sync>> {
+        let mut offset = 0u64;
+        let mut total_len = None;
+        let mut hasher = Sha256::new();
+        let mut body = Vec::new();
+        let mut tail: Vec<u8> = Vec::new();
+
+        while let Some(chunk) = fetch_chunk(&self.client, &self.bucket, key, &mut offset, &mut total_len).await? {
+            tail.extend_from_slice(&chunk);
+            if tail.len() > 32 {
+                let hashed_len = tail.len() - 32;
+                hasher.update(&tail[..hashed_len]);
+                body.extend_from_slice(&tail[..hashed_len]);
+                tail.drain(..hashed_len);
+            }
+        }
+
+        // Validate the data length (must be at least 32 bytes for checksum).
+        // NOTE: This does allow for empty data records.
+        if tail.len() != 32 {
+            return Err("Invalid record: data too short".into());
+        }
+        let checksum: [u8; 32] = tail.try_into().unwrap();
+
+        if hasher.finalize().as_slice() != checksum {
+            return Err("Checksum mismatch".into());
+        }
+
+        let (created_at_ms, content_encoding, data) = Record::decode_body(&body)?;
+
+        Ok(Record {
+            ulid,
+            data,
+            checksum,
+            created_at_ms,
+            content_encoding,
+        })
+    }
 }
 
 #[allow(clippy::upper_case_acronyms)]
@@ -146,31 +689,114 @@ impl WAL for MinioWAL {
 
     async fn read(&self, ulid: Ulid) -> Result<Record, Box<dyn Error + Send + Sync>> {
         let key = format!("wal/{}.wal", ulid.to_string());
+        self.read_chunked(ulid, &key).await
+    }
+}
+
+impl MinioWAL {
+    /// Flushes a [`Segment`] to a single S3 object, returning its key.
+    pub async fn write_segment(&self, segment: &Segment) -> Result<String, Box<dyn Error + Send + Sync>> {
+        let body = ByteStream::from(segment.to_bytes().to_vec());
+
+        self.client
+            .put_object()
+            .bucket(&self.bucket)
+            .key(segment.key())
+            .body(body)
+            .send()
+            .await?;
+
+        Ok(segment.key().to_string())
+    }
 
+    /// Reads a segment object back and decodes every framed record it contains.
+    pub async fn read_segment(&self, key: &str) -> Result<Vec<Record>, Box<dyn Error + Send + Sync>> {
         let response = self.client
             .get_object()
             .bucket(&self.bucket)
-            .key(&key)
+            .key(key)
             .send()
             .await?;
 
         let data = response.body.collect().await?.into_bytes();
 
-        // Validate the data length (must be larger than 32 bytes for checksum)
-        // NOTE: This does allow for empty data records.
-        if data.len() < 32 {
-            return Err("Invalid record: data too short".into());
-        }
+        SegmentReader::new(&data)
+            .collect::<io::Result<Vec<Record>>>()
+            .map_err(Into::into)
+    }
 
-        // Deserialize record
-        let record = Record::from_bytes(ulid, &data)?;
+    /// Streams a segment's frames out as they're decoded, pulling the segment identified by
+    /// `segment_id` in bounded `MAX_BUF_SIZE` byte-range windows instead of `read_segment`'s
+    /// full-object `collect()`. This is what keeps recovering a multi-gigabyte WAL segment from
+    /// OOM-ing: at most the current frame's encoded bytes plus one window of read-ahead, and one
+    /// decoded frame, are held at a time, not the whole segment. A frame is buffered whole before
+    /// its checksum is verified, so a frame larger than a window is held in full.
+    #[cfg(feature = "async")]
+    pub fn read_streaming(
+        &self,
+        segment_id: Ulid,
+    ) -> impl futures_util::Stream<Item = Result<Record, Box<dyn Error + Send + Sync>>> {
+        use futures_util::stream;
 
-        // Validate the checksum
-        if !record.validate_checksum() {
-            return Err("Checksum mismatch".into());
+        struct State {
+            client: Client,
+            bucket: String,
+            key: String,
+            offset: u64,
+            total_len: Option<u64>,
+            buf: Vec<u8>,
+            eof: bool,
         }
 
-        Ok(Record::from_bytes(ulid, &data)?)
+        let state = State {
+            client: self.client.clone(),
+            bucket: self.bucket.clone(),
+            key: segment_key(segment_id),
+            offset: 0,
+            total_len: None,
+            buf: Vec::new(),
+            eof: false,
+        };
+
+        stream::unfold(state, |mut state| async move {
+            loop {
+                // A full frame (`[len: u32][checksum + body: len]`) is already buffered: decode and yield it.
+                if state.buf.len() >= 4 {
+                    let declared_len = u32::from_be_bytes(state.buf[..4].try_into().unwrap()) as usize;
+                    if state.buf.len() >= 4 + declared_len {
+                        let mut slice = state.buf.as_slice();
+                        let result = Record::decode_frame(&mut slice);
+                        let consumed = state.buf.len() - slice.len();
+                        state.buf.drain(..consumed);
+                        let result = result.map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>);
+                        return Some((result, state));
+                    }
+                }
+
+                if state.eof {
+                    if state.buf.is_empty() {
+                        return None; // No bytes left and no partial frame trailing: clean end of segment.
+                    }
+                    // Bytes remain but don't form a whole frame: the segment was truncated
+                    // mid-record. Surface this the same way the eager `Segment::read` does,
+                    // rather than silently dropping the trailing bytes.
+                    let err = io::Error::new(io::ErrorKind::UnexpectedEof, "segment truncated mid-frame");
+                    state.buf.clear();
+                    return Some((Err(Box::new(err) as Box<dyn Error + Send + Sync>), state));
+                }
+
+                let chunk = match fetch_chunk(&state.client, &state.bucket, &state.key, &mut state.offset, &mut state.total_len).await {
+                    Ok(Some(chunk)) => chunk,
+                    Ok(None) => {
+                        state.eof = true;
+                        continue;
+                    }
+                    Err(e) => return Some((Err(e), state)),
+                };
+
+                state.buf.extend_from_slice(&chunk);
+            }
+        })
     }
 }
 
@@ -292,4 +918,153 @@ mod tests {
         assert_ne!(record1, record2);
         assert_ne!(record1, record3);
     }
+
+    #[test]
+    fn test_frame_round_trip() {
+        let record = Record::new(b"Framed data".to_vec());
+
+        let mut buf = Vec::new();
+        record.encode_frame(&mut buf);
+
+        let mut slice = buf.as_slice();
+        let decoded = Record::decode_frame(&mut slice).unwrap();
+
+        assert!(slice.is_empty());
+        assert_eq!(record.data, decoded.data);
+        assert_eq!(record.checksum, decoded.checksum);
+    }
+
+    #[test]
+    fn test_frame_corrupted_checksum() {
+        let record = Record::new(b"Framed data".to_vec());
+
+        let mut buf = Vec::new();
+        record.encode_frame(&mut buf);
+        let last = buf.len() - 1;
+        buf[last] ^= 0xFF; // Flip every bit of the last payload byte without touching the length prefix
+
+        let mut slice = buf.as_slice();
+        assert!(Record::decode_frame(&mut slice).is_err());
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_async_frame_round_trip() {
+        let record = Record::new(vec![b'C'; 4096]);
+
+        let mut buf = Vec::new();
+        record.try_encode_to(&mut buf).await.unwrap();
+
+        let mut reader = buf.as_slice();
+        let decoded = Record::try_decode_from(&mut reader).await.unwrap();
+
+        assert_eq!(record.data, decoded.data);
+        assert_eq!(record.checksum, decoded.checksum);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_async_frame_checksum_mismatch() {
+        let record = Record::new(b"Async data".to_vec());
+
+        let mut buf = Vec::new();
+        record.try_encode_to(&mut buf).await.unwrap();
+        let last = buf.len() - 1;
+        buf[last] ^= 0xFF;
+
+        let mut reader = buf.as_slice();
+        let err = Record::try_decode_from(&mut reader).await.unwrap_err();
+        assert!(matches!(err, DecodeError::ChecksumMismatch));
+    }
+
+    #[test]
+    fn test_segment_round_trip() {
+        let records = vec![
+            Record::new(vec![]),
+            Record::new(b"Short".to_vec()),
+            Record::new(vec![b'A'; 1024]),
+        ];
+
+        let mut segment = Segment::new();
+        for record in &records {
+            segment.push(record);
+        }
+        assert_eq!(segment.len(), records.len());
+
+        let decoded: Vec<Record> = SegmentReader::new(segment.to_bytes())
+            .collect::<io::Result<Vec<Record>>>()
+            .unwrap();
+
+        assert_eq!(decoded.len(), records.len());
+        for (original, decoded) in records.iter().zip(decoded.iter()) {
+            assert_eq!(original.data, decoded.data);
+            assert_eq!(original.checksum, decoded.checksum);
+        }
+    }
+
+    #[test]
+    fn test_tlv_metadata_round_trip() {
+        let record = Record::new(b"with metadata".to_vec())
+            .with_created_at_ms(1_700_000_000_000)
+            .with_content_encoding(b"gzip".to_vec());
+
+        assert!(record.validate_checksum());
+
+        let bytes = record.to_bytes().unwrap();
+        let decoded = Record::from_bytes(record.ulid, &bytes).unwrap();
+
+        assert_eq!(decoded.data, record.data);
+        assert_eq!(decoded.created_at_ms, Some(1_700_000_000_000));
+        assert_eq!(decoded.content_encoding, Some(b"gzip".to_vec()));
+    }
+
+    #[test]
+    fn test_unknown_odd_tlv_type_is_skipped() {
+        let mut body = Vec::new();
+        let mut header = Vec::new();
+        tlv::encode_field(&mut header, 5, b"future field"); // unknown odd type
+        tlv::encode(&mut body, header.len() as u64);
+        body.extend_from_slice(&header);
+        body.extend_from_slice(b"payload");
+
+        let (created_at_ms, content_encoding, data) = Record::decode_body(&body).unwrap();
+        assert_eq!(created_at_ms, None);
+        assert_eq!(content_encoding, None);
+        assert_eq!(data, b"payload");
+    }
+
+    #[test]
+    fn test_unknown_even_tlv_type_is_a_hard_error() {
+        let mut body = Vec::new();
+        let mut header = Vec::new();
+        tlv::encode_field(&mut header, 6, b"must understand"); // unknown even type
+        tlv::encode(&mut body, header.len() as u64);
+        body.extend_from_slice(&header);
+        body.extend_from_slice(b"payload");
+
+        assert!(Record::decode_body(&body).is_err());
+    }
+
+    #[test]
+    fn test_encode_matches_serialized_length() {
+        let record = Record::new(b"exact capacity".to_vec()).with_created_at_ms(42);
+
+        let mut counter = LengthCalculatingWriter::new();
+        record.write(&mut counter);
+        assert_eq!(counter.len(), record.serialized_length());
+
+        let encoded = record.encode();
+        assert_eq!(encoded.len(), record.serialized_length());
+    }
+
+    #[test]
+    fn test_record_read_round_trips_with_write() {
+        let record = Record::new(b"round trip via traits".to_vec()).with_content_encoding(b"identity".to_vec());
+
+        let bytes = record.encode();
+        let decoded = Record::read(&mut &bytes[..]).unwrap();
+
+        assert_eq!(decoded.data, record.data);
+        assert_eq!(decoded.content_encoding, record.content_encoding);
+    }
 }
@@ -0,0 +1,165 @@
+//! BigSize-encoded TLV (type-length-value) records, used to give [`crate::Record`] an
+//! extensible, forward/backward-compatible metadata header.
+
+use std::io::{self, Read};
+
+use crate::{DecodeError, Readable, Writeable, Writer};
+
+/// Encodes `value` as a canonical BigSize varint: a single byte when `< 0xfd`, otherwise a tag
+/// byte (`0xfd`/`0xfe`/`0xff`) followed by a big-endian `u16`/`u32`/`u64`. Always emits the
+/// minimal form, since canonical (non-minimal-rejecting) encoding is an invariant of the format.
+pub fn encode(out: &mut Vec<u8>, value: u64) {
+    if value < 0xfd {
+        out.push(value as u8);
+    } else if value <= u16::MAX as u64 {
+        out.push(0xfd);
+        out.extend_from_slice(&(value as u16).to_be_bytes());
+    } else if value <= u32::MAX as u64 {
+        out.push(0xfe);
+        out.extend_from_slice(&(value as u32).to_be_bytes());
+    } else {
+        out.push(0xff);
+        out.extend_from_slice(&value.to_be_bytes());
+    }
+}
+
+/// Decodes a BigSize varint from `r`, rejecting any non-minimal encoding.
+pub fn decode(r: &mut impl Read) -> io::Result<u64> {
+    let mut tag = [0u8; 1];
+    r.read_exact(&mut tag)?;
+
+    match tag[0] {
+        0xff => {
+            let mut buf = [0u8; 8];
+            r.read_exact(&mut buf)?;
+            let value = u64::from_be_bytes(buf);
+            if value <= u32::MAX as u64 {
+                return Err(non_canonical());
+            }
+            Ok(value)
+        }
+        0xfe => {
+            let mut buf = [0u8; 4];
+            r.read_exact(&mut buf)?;
+            let value = u32::from_be_bytes(buf) as u64;
+            if value <= u16::MAX as u64 {
+                return Err(non_canonical());
+            }
+            Ok(value)
+        }
+        0xfd => {
+            let mut buf = [0u8; 2];
+            r.read_exact(&mut buf)?;
+            let value = u16::from_be_bytes(buf) as u64;
+            if value < 0xfd {
+                return Err(non_canonical());
+            }
+            Ok(value)
+        }
+        b => Ok(b as u64),
+    }
+}
+
+fn non_canonical() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, "non-canonical BigSize encoding")
+}
+
+/// A single decoded `(type, value)` TLV entry; `length` is implied by `value.len()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Field {
+    pub kind: u64,
+    pub value: Vec<u8>,
+}
+
+/// Appends one TLV entry (`type`, `length`, `value`) to `out`.
+pub fn encode_field(out: &mut Vec<u8>, kind: u64, value: &[u8]) {
+    encode(out, kind);
+    encode(out, value.len() as u64);
+    out.extend_from_slice(value);
+}
+
+impl Writeable for Field {
+    fn write(&self, w: &mut impl Writer) {
+        let mut prefix = Vec::new();
+        encode(&mut prefix, self.kind);
+        encode(&mut prefix, self.value.len() as u64);
+        w.write_all(&prefix);
+        w.write_all(&self.value);
+    }
+}
+
+impl Readable for Field {
+    fn read(r: &mut impl Read) -> Result<Self, DecodeError> {
+        let kind = decode(r)?;
+        let length = decode(r)? as usize;
+        let mut value = vec![0u8; length];
+        r.read_exact(&mut value)?;
+        Ok(Field { kind, value })
+    }
+}
+
+/// Decodes every TLV entry in `bytes`, requiring type values to be strictly increasing with no
+/// duplicates. Callers are responsible for the odd/even tolerance rule: an unrecognized **odd**
+/// type may be skipped, but an unrecognized **even** type must be treated as a hard error.
+pub fn decode_stream(bytes: &[u8]) -> io::Result<Vec<Field>> {
+    let mut r = bytes;
+    let mut fields = Vec::new();
+    let mut last_kind: Option<u64> = None;
+
+    while !r.is_empty() {
+        let field = Field::read(&mut r)?;
+        if last_kind.is_some_and(|last| field.kind <= last) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "TLV types must be strictly increasing with no duplicates",
+            ));
+        }
+        last_kind = Some(field.kind);
+        fields.push(field);
+    }
+
+    Ok(fields)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bigsize_round_trip() {
+        for value in [0u64, 0xfc, 0xfd, 0xffff, 0x10000, u32::MAX as u64, u32::MAX as u64 + 1, u64::MAX] {
+            let mut buf = Vec::new();
+            encode(&mut buf, value);
+            let decoded = decode(&mut buf.as_slice()).unwrap();
+            assert_eq!(value, decoded);
+        }
+    }
+
+    #[test]
+    fn test_bigsize_rejects_non_minimal() {
+        // 0xfd followed by a u16 that fits in a single byte is non-canonical.
+        let buf = [0xfdu8, 0x00, 0x05];
+        assert!(decode(&mut &buf[..]).is_err());
+    }
+
+    #[test]
+    fn test_tlv_stream_rejects_out_of_order_types() {
+        let mut buf = Vec::new();
+        encode_field(&mut buf, 3, b"b");
+        encode_field(&mut buf, 1, b"a");
+        assert!(decode_stream(&buf).is_err());
+    }
+
+    #[test]
+    fn test_tlv_stream_round_trip() {
+        let mut buf = Vec::new();
+        encode_field(&mut buf, 1, b"created-at");
+        encode_field(&mut buf, 3, b"gzip");
+
+        let fields = decode_stream(&buf).unwrap();
+        assert_eq!(fields, vec![
+            Field { kind: 1, value: b"created-at".to_vec() },
+            Field { kind: 3, value: b"gzip".to_vec() },
+        ]);
+    }
+}